@@ -0,0 +1,284 @@
+/*!
+A validator that checks Smithy's constraint traits (`length`, `range`, `pattern`, `uniqueItems`,
+`required`, `enum`) are applied to compatible targets and carry internally consistent bounds.
+*/
+
+use crate::action::{Action, ActionIssue, IssueLevel, Validator};
+use crate::model::shapes::{HasTraits, ShapeKind, Simple};
+use crate::model::values::{Number, Value as NodeValue};
+use crate::model::{HasIdentity, Model};
+use crate::prelude::{
+    PRELUDE_NAMESPACE, TRAIT_ENUM, TRAIT_LENGTH, TRAIT_PATTERN, TRAIT_RANGE, TRAIT_REQUIRED,
+    TRAIT_UNIQUEITEMS,
+};
+use regex::Regex;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Walks every shape and member in a model and reports constraint traits that are attached to an
+/// incompatible shape kind, or whose `min`/`max` bounds are contradictory (`min > max`), or whose
+/// `pattern` value does not compile as a regular expression. A member is checked against the kind
+/// of its target shape only when that target is in the model; traits on members whose target is
+/// not in the model (such as a prelude shape like `smithy.api#String`) are not checked.
+///
+#[derive(Debug)]
+pub struct ConstraintTraits {}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for ConstraintTraits {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Action for ConstraintTraits {
+    fn label(&self) -> &'static str {
+        "ConstraintTraits"
+    }
+}
+
+impl Validator for ConstraintTraits {
+    fn validate(&mut self, model: &Model) -> Vec<ActionIssue> {
+        let mut issues = Vec::new();
+
+        for shape in model.shapes() {
+            for a_trait in shape.traits() {
+                self.check_trait(
+                    model,
+                    shape.id().to_string(),
+                    a_trait,
+                    shape.body(),
+                    false,
+                    &mut issues,
+                );
+            }
+            if let ShapeKind::Structure(body) | ShapeKind::Union(body) = shape.body() {
+                for member in body.members() {
+                    let target_kind = model
+                        .shape(member.target())
+                        .map(|target| target.body().clone());
+                    for a_trait in member.traits() {
+                        if let Some(kind) = &target_kind {
+                            self.check_trait(
+                                model,
+                                member.id().to_string(),
+                                a_trait,
+                                kind,
+                                true,
+                                &mut issues,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+impl ConstraintTraits {
+    fn check_trait(
+        &self,
+        _model: &Model,
+        locus: String,
+        a_trait: &crate::model::shapes::AppliedTrait,
+        target: &ShapeKind,
+        is_member: bool,
+        issues: &mut Vec<ActionIssue>,
+    ) {
+        if a_trait.id().namespace().map(|ns| ns.to_string()).as_deref() != Some(PRELUDE_NAMESPACE) {
+            return;
+        }
+        let trait_name = a_trait.id().shape_name().to_string();
+        match trait_name.as_str() {
+            TRAIT_LENGTH => {
+                if !matches!(
+                    target,
+                    ShapeKind::Simple(Simple::String)
+                        | ShapeKind::Simple(Simple::Blob)
+                        | ShapeKind::List(_)
+                        | ShapeKind::Set(_)
+                        | ShapeKind::Map(_)
+                ) {
+                    issues.push(ActionIssue::new(
+                        IssueLevel::Error,
+                        format!(
+                            "`length` trait applied to `{}`, which is not a string, blob, list, set, or map",
+                            locus
+                        ),
+                    ));
+                } else if let Some(value) = a_trait.value() {
+                    self.check_bounds("length", &locus, value, issues);
+                }
+            }
+            TRAIT_RANGE => {
+                if !matches!(target, ShapeKind::Simple(s) if s.is_numeric()) {
+                    issues.push(ActionIssue::new(
+                        IssueLevel::Error,
+                        format!("`range` trait applied to `{}`, which is not a numeric shape", locus),
+                    ));
+                } else if let Some(value) = a_trait.value() {
+                    self.check_bounds("range", &locus, value, issues);
+                }
+            }
+            TRAIT_PATTERN => {
+                if !matches!(target, ShapeKind::Simple(Simple::String)) {
+                    issues.push(ActionIssue::new(
+                        IssueLevel::Error,
+                        format!("`pattern` trait applied to `{}`, which is not a string", locus),
+                    ));
+                } else if let Some(NodeValue::String(pattern)) = a_trait.value() {
+                    if Regex::new(pattern).is_err() {
+                        issues.push(ActionIssue::new(
+                            IssueLevel::Error,
+                            format!("`pattern` trait on `{}` does not compile: `{}`", locus, pattern),
+                        ));
+                    }
+                }
+            }
+            TRAIT_UNIQUEITEMS => {
+                if !matches!(target, ShapeKind::List(_)) {
+                    issues.push(ActionIssue::new(
+                        IssueLevel::Error,
+                        format!("`uniqueItems` trait applied to `{}`, which is not a list", locus),
+                    ));
+                }
+            }
+            TRAIT_REQUIRED => {
+                if !is_member {
+                    issues.push(ActionIssue::new(
+                        IssueLevel::Error,
+                        format!(
+                            "`required` trait applied to `{}`, which is not a structure/union member",
+                            locus
+                        ),
+                    ));
+                }
+            }
+            TRAIT_ENUM => {
+                if !matches!(target, ShapeKind::Simple(Simple::String)) {
+                    issues.push(ActionIssue::new(
+                        IssueLevel::Error,
+                        format!("`enum` trait applied to `{}`, which is not a string", locus),
+                    ));
+                } else if let Some(NodeValue::Array(values)) = a_trait.value() {
+                    let mut seen = std::collections::HashSet::new();
+                    for entry in values {
+                        if let NodeValue::Object(entry) = entry {
+                            if let Some(NodeValue::String(value)) = entry.get("value") {
+                                if !seen.insert(value.clone()) {
+                                    issues.push(ActionIssue::new(
+                                        IssueLevel::Error,
+                                        format!(
+                                            "`enum` trait on `{}` declares the value `{}` more than once",
+                                            locus, value
+                                        ),
+                                    ));
+                                }
+                            } else {
+                                issues.push(ActionIssue::new(
+                                    IssueLevel::Error,
+                                    format!(
+                                        "`enum` trait on `{}` has a definition with no `value`",
+                                        locus
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_bounds(&self, trait_name: &str, locus: &str, value: &NodeValue, issues: &mut Vec<ActionIssue>) {
+        if let NodeValue::Object(bounds) = value {
+            let min = bounds.get("min").and_then(as_f64);
+            let max = bounds.get("max").and_then(as_f64);
+            if let (Some(min), Some(max)) = (min, max) {
+                if min > max {
+                    issues.push(ActionIssue::new(
+                        IssueLevel::Error,
+                        format!(
+                            "`{}` trait on `{}` has min ({}) greater than max ({})",
+                            trait_name, locus, min, max
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn as_f64(value: &NodeValue) -> Option<f64> {
+    match value {
+        NodeValue::Number(Number::Integer(v)) => Some(*v as f64),
+        NodeValue::Number(Number::Float(v)) => Some(*v),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::shapes::{AppliedTrait, HasTraits, Simple, TopLevelShape};
+    use crate::model::{HasIdentity, Model, NamespaceID};
+    use crate::Version;
+
+    fn prelude_trait(name: &str) -> AppliedTrait {
+        let prelude: NamespaceID = PRELUDE_NAMESPACE.parse().unwrap();
+        AppliedTrait::new(prelude.make_shape(name.parse().unwrap()))
+    }
+
+    #[test]
+    fn required_on_a_top_level_shape_is_an_error() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let mut shape = TopLevelShape::new(
+            namespace.make_shape("Date".parse().unwrap()),
+            ShapeKind::Simple(Simple::String),
+        );
+        shape.apply_trait(prelude_trait(TRAIT_REQUIRED));
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(shape);
+
+        let issues = ConstraintTraits::default().validate(&model);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].level(), IssueLevel::Error);
+    }
+
+    #[test]
+    fn enum_with_duplicate_values_is_an_error() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let mut shape = TopLevelShape::new(
+            namespace.make_shape("Suit".parse().unwrap()),
+            ShapeKind::Simple(Simple::String),
+        );
+        let mut enum_trait = prelude_trait(TRAIT_ENUM);
+        let mut entry = std::collections::HashMap::new();
+        let _ = entry.insert(
+            "value".to_string(),
+            NodeValue::String("DIAMOND".to_string()),
+        );
+        enum_trait.set_value(NodeValue::Array(vec![
+            NodeValue::Object(entry.clone()),
+            NodeValue::Object(entry),
+        ]));
+        shape.apply_trait(enum_trait);
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(shape);
+
+        let issues = ConstraintTraits::default().validate(&model);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].level(), IssueLevel::Error);
+    }
+}
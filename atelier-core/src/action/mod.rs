@@ -0,0 +1,93 @@
+/*!
+Model actions: linters, validators, and transformations that either report on a
+[Model](../model/struct.Model.html) or rewrite it outright.
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The severity of a single [`ActionIssue`](struct.ActionIssue.html).
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueLevel {
+    /// Informational; no action required.
+    Info,
+    /// Likely a mistake, but not invalid.
+    Warning,
+    /// The model violates a rule and should be corrected.
+    Error,
+}
+
+///
+/// A single issue reported by a [`Linter`](trait.Linter.html) or [`Validator`](trait.Validator.html).
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionIssue {
+    level: IssueLevel,
+    message: String,
+}
+
+///
+/// Common identity shared by every linter, validator, and transform.
+///
+pub trait Action {
+    /// A short, human-readable label identifying this action, used in reporting.
+    fn label(&self) -> &'static str;
+}
+
+///
+/// An action that inspects a model for style or best-practice issues without changing it.
+///
+pub trait Linter: Action {
+    /// Check `model` and return any issues found.
+    fn lint(&mut self, model: &crate::model::Model) -> Vec<ActionIssue>;
+}
+
+///
+/// An action that inspects a model for correctness issues without changing it.
+///
+pub trait Validator: Action {
+    /// Validate `model` and return any issues found.
+    fn validate(&mut self, model: &crate::model::Model) -> Vec<ActionIssue>;
+}
+
+///
+/// An action that consumes a model and produces a new, rewritten, one.
+///
+pub trait Transform: Action {
+    /// Transform `model`, producing a new model or an error.
+    fn transform(&mut self, model: crate::model::Model) -> crate::error::Result<crate::model::Model>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ActionIssue {
+    /// Create a new issue at the given `level` with the given `message`.
+    pub fn new(level: IssueLevel, message: String) -> Self {
+        Self { level, message }
+    }
+
+    /// This issue's severity.
+    pub fn level(&self) -> IssueLevel {
+        self.level
+    }
+
+    /// This issue's human-readable message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod constraint;
+
+pub mod paginated;
+
+pub mod transform;
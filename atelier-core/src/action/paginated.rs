@@ -0,0 +1,340 @@
+/*!
+A validator that checks the `paginated` trait (see
+[`model::paginated`](../../model/paginated/index.html)) is internally consistent: the referenced
+`inputToken`/`pageSize` members exist on the operation's input, `outputToken`/`items` exist on its
+output, both tokens are string-typed, and `items` targets a list or map.
+*/
+
+use crate::action::{Action, ActionIssue, IssueLevel, Validator};
+use crate::model::paginated::Paginated;
+use crate::model::shapes::{HasTraits, ShapeKind, Simple, StructureOrUnion};
+use crate::model::{HasIdentity, Model};
+use crate::prelude::PRELUDE_NAMESPACE;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Validates the effective `paginated` configuration (after inheriting any default from the
+/// owning service) of every operation listed directly in a service's `operations`. Operations
+/// bound only through a resource are not checked.
+///
+#[derive(Debug)]
+pub struct PaginationConsistency {}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for PaginationConsistency {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Action for PaginationConsistency {
+    fn label(&self) -> &'static str {
+        "PaginationConsistency"
+    }
+}
+
+impl Validator for PaginationConsistency {
+    fn validate(&mut self, model: &Model) -> Vec<ActionIssue> {
+        let mut issues = Vec::new();
+
+        for shape in model.shapes() {
+            let service = match shape.body() {
+                ShapeKind::Service(v) => v,
+                _ => continue,
+            };
+            let service_default = Paginated::from_shape(shape).unwrap_or_default();
+
+            for op_id in service.operations() {
+                let op_shape = match model.shape(op_id) {
+                    Some(s) => s,
+                    None => continue,
+                };
+                let operation = match op_shape.body() {
+                    ShapeKind::Operation(v) => v,
+                    _ => continue,
+                };
+                let paginated = match Paginated::from_shape(op_shape) {
+                    Some(p) => p.inherit(&service_default),
+                    None if service_default != Paginated::default() => service_default.clone(),
+                    None => continue,
+                };
+
+                let input = operation.input().and_then(|id| model.shape(id));
+                let output = operation.output().and_then(|id| model.shape(id));
+
+                self.check_token(&paginated.input_token, input, model, op_id.to_string(), &mut issues);
+                if let Some(page_size) = &paginated.page_size {
+                    if self.find_member(input, page_size, model).is_none() {
+                        issues.push(ActionIssue::new(
+                            IssueLevel::Error,
+                            format!("paginated member `{}` not found on `{}`", page_size, op_id),
+                        ));
+                    }
+                }
+                self.check_token(&paginated.output_token, output, model, op_id.to_string(), &mut issues);
+                self.check_items(&paginated.items, output, model, op_id.to_string(), &mut issues);
+            }
+        }
+
+        issues
+    }
+}
+
+impl PaginationConsistency {
+    fn check_token(
+        &self,
+        member_name: &Option<String>,
+        structure: Option<&crate::model::shapes::TopLevelShape>,
+        model: &Model,
+        locus: String,
+        issues: &mut Vec<ActionIssue>,
+    ) {
+        let member_name = match member_name {
+            Some(v) => v,
+            None => return,
+        };
+        match self.find_member(structure, member_name, model) {
+            None => issues.push(ActionIssue::new(
+                IssueLevel::Error,
+                format!("paginated member `{}` not found on `{}`", member_name, locus),
+            )),
+            Some(is_string) if !is_string => issues.push(ActionIssue::new(
+                IssueLevel::Error,
+                format!("paginated token member `{}` on `{}` is not a string", member_name, locus),
+            )),
+            Some(_) => {}
+        }
+    }
+
+    fn check_items(
+        &self,
+        member_name: &Option<String>,
+        structure: Option<&crate::model::shapes::TopLevelShape>,
+        model: &Model,
+        locus: String,
+        issues: &mut Vec<ActionIssue>,
+    ) {
+        let member_name = match member_name {
+            Some(v) => v,
+            None => return,
+        };
+        let body = match structure.map(|s| s.body()) {
+            Some(ShapeKind::Structure(body)) | Some(ShapeKind::Union(body)) => body,
+            _ => {
+                issues.push(ActionIssue::new(
+                    IssueLevel::Error,
+                    format!("paginated `items` member `{}` not found on `{}`", member_name, locus),
+                ));
+                return;
+            }
+        };
+        match member_target_kind(body, member_name, model) {
+            None => issues.push(ActionIssue::new(
+                IssueLevel::Error,
+                format!("paginated `items` member `{}` not found on `{}`", member_name, locus),
+            )),
+            Some(ShapeKind::List(_)) | Some(ShapeKind::Map(_)) => {}
+            Some(_) => issues.push(ActionIssue::new(
+                IssueLevel::Error,
+                format!(
+                    "paginated `items` member `{}` on `{}` does not target a list or map",
+                    member_name, locus
+                ),
+            )),
+        }
+    }
+
+    fn find_member(
+        &self,
+        structure: Option<&crate::model::shapes::TopLevelShape>,
+        member_name: &str,
+        model: &Model,
+    ) -> Option<bool> {
+        let body = match structure.map(|s| s.body()) {
+            Some(ShapeKind::Structure(body)) | Some(ShapeKind::Union(body)) => body,
+            _ => return None,
+        };
+        let member = body
+            .members()
+            .find(|m| m.id().member_name().map(|n| n.to_string()).as_deref() == Some(member_name))?;
+        Some(match model.shape(member.target()) {
+            Some(target) => matches!(target.body(), ShapeKind::Simple(Simple::String)),
+            None => member.target().to_string() == format!("{}#String", PRELUDE_NAMESPACE),
+        })
+    }
+}
+
+fn member_target_kind<'a>(
+    body: &StructureOrUnion,
+    member_name: &str,
+    model: &'a Model,
+) -> Option<&'a ShapeKind> {
+    let member = body
+        .members()
+        .find(|m| m.id().member_name().map(|n| n.to_string()).as_deref() == Some(member_name))?;
+    model.shape(member.target()).map(|s| s.body())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::shapes::{AppliedTrait, MemberShape, Operation, Service, StructureOrUnion, TopLevelShape};
+    use crate::model::values::Value as NodeValue;
+    use crate::model::NamespaceID;
+    use crate::prelude::TRAIT_PAGINATED;
+    use crate::Version;
+
+    fn paginated_trait(input_token: &str, output_token: &str) -> AppliedTrait {
+        let prelude: NamespaceID = "smithy.api".parse().unwrap();
+        let mut value = std::collections::HashMap::new();
+        let _ = value.insert(
+            "inputToken".to_string(),
+            NodeValue::String(input_token.to_string()),
+        );
+        let _ = value.insert(
+            "outputToken".to_string(),
+            NodeValue::String(output_token.to_string()),
+        );
+        AppliedTrait::with_value(
+            prelude.make_shape(TRAIT_PAGINATED.parse().unwrap()),
+            NodeValue::Object(value),
+        )
+    }
+
+    fn string_target() -> crate::model::ShapeID {
+        let prelude: NamespaceID = "smithy.api".parse().unwrap();
+        prelude.make_shape("String".parse().unwrap())
+    }
+
+    #[test]
+    fn missing_paginated_member_is_an_error() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+
+        let input_id = namespace.make_shape("ListInput".parse().unwrap());
+        let input = TopLevelShape::new(input_id.clone(), ShapeKind::Structure(StructureOrUnion::new()));
+
+        let mut output = Operation::default();
+        output.set_input(input_id);
+        let mut operation = TopLevelShape::new(
+            namespace.make_shape("List".parse().unwrap()),
+            ShapeKind::Operation(output),
+        );
+        operation.apply_trait(paginated_trait("nextToken", "nextToken"));
+
+        let mut service = Service::new("2020-01-01");
+        service.add_operation(operation.id().clone());
+        let service = TopLevelShape::new(
+            namespace.make_shape("Widgets".parse().unwrap()),
+            ShapeKind::Service(service),
+        );
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(input);
+        model.add_shape(operation);
+        model.add_shape(service);
+
+        let issues = PaginationConsistency::default().validate(&model);
+        assert!(!issues.is_empty());
+        assert!(issues.iter().all(|i| i.level() == IssueLevel::Error));
+    }
+
+    #[test]
+    fn consistent_pagination_has_no_issues() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+
+        let input_id = namespace.make_shape("ListInput".parse().unwrap());
+        let mut input_body = StructureOrUnion::new();
+        let _ = input_body.add_a_member(MemberShape::new(
+            input_id.make_member("nextToken".parse().unwrap()),
+            string_target(),
+        ));
+        let input = TopLevelShape::new(input_id.clone(), ShapeKind::Structure(input_body));
+
+        let output_id = namespace.make_shape("ListOutput".parse().unwrap());
+        let mut output_body = StructureOrUnion::new();
+        let _ = output_body.add_a_member(MemberShape::new(
+            output_id.make_member("nextToken".parse().unwrap()),
+            string_target(),
+        ));
+        let output = TopLevelShape::new(output_id.clone(), ShapeKind::Structure(output_body));
+
+        let mut op = Operation::default();
+        op.set_input(input_id);
+        op.set_output(output_id);
+        let mut operation = TopLevelShape::new(
+            namespace.make_shape("List".parse().unwrap()),
+            ShapeKind::Operation(op),
+        );
+        operation.apply_trait(paginated_trait("nextToken", "nextToken"));
+
+        let mut service = Service::new("2020-01-01");
+        service.add_operation(operation.id().clone());
+        let service = TopLevelShape::new(
+            namespace.make_shape("Widgets".parse().unwrap()),
+            ShapeKind::Service(service),
+        );
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(input);
+        model.add_shape(output);
+        model.add_shape(operation);
+        model.add_shape(service);
+
+        let issues = PaginationConsistency::default().validate(&model);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn a_token_member_targeting_a_custom_string_shape_is_not_an_error() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+
+        let custom_string = TopLevelShape::new(
+            namespace.make_shape("NextToken".parse().unwrap()),
+            ShapeKind::Simple(Simple::String),
+        );
+
+        let input_id = namespace.make_shape("ListInput".parse().unwrap());
+        let mut input_body = StructureOrUnion::new();
+        let _ = input_body.add_a_member(MemberShape::new(
+            input_id.make_member("nextToken".parse().unwrap()),
+            custom_string.id().clone(),
+        ));
+        let input = TopLevelShape::new(input_id.clone(), ShapeKind::Structure(input_body));
+
+        let mut op = Operation::default();
+        op.set_input(input_id);
+        let mut operation = TopLevelShape::new(
+            namespace.make_shape("List".parse().unwrap()),
+            ShapeKind::Operation(op),
+        );
+        operation.apply_trait(paginated_trait("nextToken", "nextToken"));
+
+        let mut service = Service::new("2020-01-01");
+        service.add_operation(operation.id().clone());
+        let service = TopLevelShape::new(
+            namespace.make_shape("Widgets".parse().unwrap()),
+            ShapeKind::Service(service),
+        );
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(custom_string);
+        model.add_shape(input);
+        model.add_shape(operation);
+        model.add_shape(service);
+
+        let issues = PaginationConsistency::default().validate(&model);
+        assert!(
+            issues
+                .iter()
+                .all(|i| !i.message().contains("is not a string")),
+            "unexpected issues: {:?}",
+            issues
+        );
+    }
+}
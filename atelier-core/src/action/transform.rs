@@ -0,0 +1,249 @@
+/*!
+Transformations over a [Model](../../model/struct.Model.html) that rewrite the model itself, as
+opposed to [`Validator`](../trait.Validator.html)s and [`Linter`](../trait.Linter.html)s which only
+report on it.
+*/
+
+use crate::action::{Action, Transform};
+use crate::error::{ErrorKind, Result};
+use crate::model::shapes::{HasTraits, ShapeKind};
+use crate::model::{HasIdentity, Model, NamespaceID, ShapeID};
+use crate::prelude::PRELUDE_NAMESPACE;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Rewrites every relative [`ShapeID`](../../model/struct.ShapeID.html) appearing in a model —
+/// member targets, operation input/output/errors, resource identifiers and lifecycle bindings, and
+/// trait references — into an absolute one, resolved first against the model's own (ambient)
+/// namespace and then against the prelude namespace. Only the prelude's simple shape names (see
+/// `PRELUDE_SHAPE_NAMES`) resolve against the prelude; a relative reference to a prelude trait,
+/// such as `required`, does not. Produces an `ErrorKind::UnresolvedShapeId` for any reference
+/// that resolves in neither way.
+///
+#[derive(Debug)]
+pub struct ResolveShapeIds {
+    namespace: NamespaceID,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ResolveShapeIds {
+    ///
+    /// Create a new transform that resolves relative shape IDs against `namespace`.
+    ///
+    pub fn new(namespace: NamespaceID) -> Self {
+        Self { namespace }
+    }
+}
+
+impl Action for ResolveShapeIds {
+    fn label(&self) -> &'static str {
+        "ResolveShapeIds"
+    }
+}
+
+impl Transform for ResolveShapeIds {
+    fn transform(&mut self, model: Model) -> Result<Model> {
+        let mut resolved = Model::new(model.smithy_version().clone());
+        for (key, value) in model.metadata() {
+            resolved.add_metadata(key.clone(), value.clone());
+        }
+
+        for mut shape in model.shapes().cloned() {
+            let resolved_traits = shape
+                .traits()
+                .iter()
+                .cloned()
+                .map(|mut a_trait| {
+                    let resolved_id = self.resolve(&model, a_trait.id())?;
+                    a_trait.set_id(resolved_id);
+                    Ok(a_trait)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            shape.set_traits(resolved_traits);
+
+            match shape.body_mut() {
+                ShapeKind::List(v) => *v.member_mut().target_mut() = self.resolve(&model, v.member().target())?,
+                ShapeKind::Set(v) => *v.member_mut().target_mut() = self.resolve(&model, v.member().target())?,
+                ShapeKind::Map(v) => {
+                    *v.key_mut().target_mut() = self.resolve(&model, v.key().target())?;
+                    *v.value_mut().target_mut() = self.resolve(&model, v.value().target())?;
+                }
+                ShapeKind::Structure(v) | ShapeKind::Union(v) => {
+                    for member in v.members_mut() {
+                        let resolved_target = self.resolve(&model, member.target())?;
+                        *member.target_mut() = resolved_target;
+                    }
+                }
+                ShapeKind::Service(v) => {
+                    let operations = v
+                        .operations()
+                        .map(|id| self.resolve(&model, id))
+                        .collect::<Result<Vec<_>>>()?;
+                    v.set_operations(operations);
+                    let resources = v
+                        .resources()
+                        .map(|id| self.resolve(&model, id))
+                        .collect::<Result<Vec<_>>>()?;
+                    v.set_resources(resources);
+                }
+                ShapeKind::Operation(v) => {
+                    if let Some(id) = v.input() {
+                        v.set_input(self.resolve(&model, id)?);
+                    }
+                    if let Some(id) = v.output() {
+                        v.set_output(self.resolve(&model, id)?);
+                    }
+                    let errors = v
+                        .errors()
+                        .map(|id| self.resolve(&model, id))
+                        .collect::<Result<Vec<_>>>()?;
+                    v.set_errors(errors);
+                }
+                ShapeKind::Resource(v) => {
+                    for (_, target) in v.identifiers_mut() {
+                        *target = self.resolve(&model, target)?;
+                    }
+                    for id in [v.create(), v.put(), v.read(), v.update(), v.delete(), v.list()]
+                        .into_iter()
+                        .flatten()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                    {
+                        let resolved_id = self.resolve(&model, &id)?;
+                        v.replace_lifecycle_binding(&id, resolved_id);
+                    }
+                }
+                ShapeKind::Simple(_) | ShapeKind::Unresolved => {}
+            }
+            resolved.add_shape(shape);
+        }
+
+        Ok(resolved)
+    }
+}
+
+impl ResolveShapeIds {
+    fn resolve(&self, model: &Model, id: &ShapeID) -> Result<ShapeID> {
+        if id.namespace().is_some() {
+            return Ok(id.clone());
+        }
+
+        let in_namespace = self.namespace.make_shape(id.shape_name().clone());
+        if model.shape(&in_namespace).is_some() {
+            return Ok(in_namespace);
+        }
+
+        let shape_name = id.shape_name().to_string();
+        if PRELUDE_SHAPE_NAMES.contains(&shape_name.as_str()) {
+            let prelude: NamespaceID = PRELUDE_NAMESPACE.parse().unwrap();
+            return Ok(prelude.make_shape(id.shape_name().clone()));
+        }
+
+        Err(ErrorKind::UnresolvedShapeId(id.to_string()).into())
+    }
+}
+
+///
+/// The simple shape names defined by the Smithy prelude (`smithy.api`); the prelude's trait
+/// shapes are not listed. A relative reference to one of
+/// these resolves against the prelude even when the prelude's own shapes are not merged into the
+/// model being resolved, which is the common case — `ModelAssembler` and model readers do not
+/// populate a model's shape map with prelude shapes.
+///
+const PRELUDE_SHAPE_NAMES: &[&str] = &[
+    "Blob",
+    "Boolean",
+    "Document",
+    "String",
+    "Byte",
+    "Short",
+    "Integer",
+    "Long",
+    "Float",
+    "Double",
+    "BigInteger",
+    "BigDecimal",
+    "Timestamp",
+    "PrimitiveBoolean",
+    "PrimitiveByte",
+    "PrimitiveShort",
+    "PrimitiveInteger",
+    "PrimitiveLong",
+    "PrimitiveFloat",
+    "PrimitiveDouble",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::shapes::{MemberShape, Operation, StructureOrUnion, TopLevelShape};
+    use crate::Version;
+
+    #[test]
+    fn relative_references_resolve_against_the_ambient_namespace_and_the_prelude() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+
+        let date_id = namespace.make_shape("Date".parse().unwrap());
+        let date = TopLevelShape::new(date_id, ShapeKind::Simple(crate::model::shapes::Simple::String));
+
+        let thing_id = namespace.make_shape("Thing".parse().unwrap());
+        let mut body = StructureOrUnion::new();
+        let _ = body.add_a_member(MemberShape::new(
+            thing_id.make_member("date".parse().unwrap()),
+            "Date".parse().unwrap(),
+        ));
+        let _ = body.add_a_member(MemberShape::new(
+            thing_id.make_member("label".parse().unwrap()),
+            "String".parse().unwrap(),
+        ));
+        let thing = TopLevelShape::new(thing_id.clone(), ShapeKind::Structure(body));
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(date);
+        model.add_shape(thing);
+
+        let resolved = ResolveShapeIds::new(namespace).transform(model).unwrap();
+
+        let resolved_thing = resolved.shape(&thing_id).unwrap();
+        let body = match resolved_thing.body() {
+            ShapeKind::Structure(v) => v,
+            _ => panic!("expected a structure"),
+        };
+        let date_target = body
+            .members()
+            .find(|m| m.id().member_name().unwrap().to_string() == "date")
+            .unwrap()
+            .target();
+        assert_eq!(date_target.to_string(), "example.motd#Date");
+        let label_target = body
+            .members()
+            .find(|m| m.id().member_name().unwrap().to_string() == "label")
+            .unwrap()
+            .target();
+        assert_eq!(label_target.to_string(), "smithy.api#String");
+    }
+
+    #[test]
+    fn a_reference_that_resolves_in_neither_namespace_is_unresolved() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+
+        let mut op = Operation::default();
+        op.set_input("MissingInput".parse().unwrap());
+        let operation = TopLevelShape::new(
+            namespace.make_shape("Get".parse().unwrap()),
+            ShapeKind::Operation(op),
+        );
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(operation);
+
+        let result = ResolveShapeIds::new(namespace).transform(model);
+        assert!(result.is_err());
+    }
+}
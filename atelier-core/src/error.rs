@@ -0,0 +1,91 @@
+/*!
+Common error, and `Result`, types for this crate, and other Atelier crates, built with the
+[`error_chain`](https://docs.rs/error-chain) crate.
+*/
+
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+error_chain! {
+    errors {
+        /// A model could not be serialized into the named representation.
+        Serialization(repr: String) {
+            description("could not serialize model")
+            display("could not serialize model into the '{}' representation", repr)
+        }
+        /// A model could not be deserialized from the named representation.
+        Deserialization(repr: String) {
+            description("could not deserialize model")
+            display("could not deserialize model from the '{}' representation", repr)
+        }
+        /// A string did not match a known Smithy specification version number.
+        InvalidVersionNumber(value: String) {
+            description("invalid Smithy version number")
+            display("'{}' is not a known Smithy version number", value)
+        }
+        /// Two sources given to a [`ModelAssembler`](../io/assembler/struct.ModelAssembler.html)
+        /// declared different Smithy versions.
+        AssemblerVersionMismatch(first: String, second: String) {
+            description("model sources disagree on Smithy version")
+            display("model sources disagree on Smithy version: '{}' vs '{}'", first, second)
+        }
+        /// Two sources given to a [`ModelAssembler`](../io/assembler/struct.ModelAssembler.html)
+        /// defined the same absolute shape ID with differing bodies.
+        AssemblerShapeConflict(shape_id: String) {
+            description("conflicting shape definition during model assembly")
+            display("shape '{}' is defined differently by two merged sources", shape_id)
+        }
+        /// A [`ModelAssembler`](../io/assembler/struct.ModelAssembler.html) found a shape reference
+        /// (a trait ID, member target, or other binding) that is still relative once all sources
+        /// have been merged.
+        AssemblerUnresolvedReference(shape_id: String) {
+            description("unresolved shape reference after model assembly")
+            display("'{}' does not resolve to an absolute shape ID", shape_id)
+        }
+        /// Two sources given to a [`ModelAssembler`](../io/assembler/struct.ModelAssembler.html)
+        /// set the same non-array metadata key to different values.
+        AssemblerMetadataConflict(key: String) {
+            description("conflicting metadata value during model assembly")
+            display("metadata key '{}' is set to different values by two merged sources", key)
+        }
+        /// A [`ResolveShapeIds`](../action/transform/struct.ResolveShapeIds.html) transform could
+        /// not resolve a relative shape ID against either the target namespace or the prelude.
+        UnresolvedShapeId(shape_id: String) {
+            description("could not resolve relative shape ID")
+            display("'{}' does not resolve to a shape in the target namespace or the prelude", shape_id)
+        }
+        /// A [`Selector`](../model/selector/struct.Selector.html) string did not parse as a valid
+        /// selector expression.
+        InvalidSelectorExpression(expression: String) {
+            description("invalid selector expression")
+            display("'{}' is not a valid selector expression", expression)
+        }
+    }
+}
+
+///
+/// Identifies which party is at fault for an operation error, per the Smithy `error` trait.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorSource {
+    /// The client sent an invalid request.
+    Client,
+    /// The server failed to process an otherwise valid request.
+    Server,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ErrorSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Client => write!(f, "client"),
+            Self::Server => write!(f, "server"),
+        }
+    }
+}
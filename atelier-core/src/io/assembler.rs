@@ -0,0 +1,271 @@
+/*!
+Assembles a single [Model](../../model/struct.Model.html) out of multiple source files, the way the
+Smithy CLI loads a directory of `.smithy`/JSON AST files. Each source is parsed independently with
+whatever [`ModelReader`](../trait.ModelReader.html) fits its representation, and the resulting
+models are merged: shape maps are unioned, metadata objects are merged per the Smithy merge rules,
+and all sources must agree on the model [`Version`](../../struct.Version.html).
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::shapes::{HasTraits, ShapeKind};
+use crate::model::values::Value as NodeValue;
+use crate::model::{HasIdentity, Model, ShapeID};
+use crate::Version;
+use std::collections::HashMap;
+use std::io::Read;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Accumulates shapes and metadata from multiple [Model](../../model/struct.Model.html) sources
+/// into a single merged model.
+///
+#[derive(Debug)]
+pub struct ModelAssembler {
+    version: Option<Version>,
+    model: Model,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for ModelAssembler {
+    fn default() -> Self {
+        Self {
+            version: None,
+            model: Model::new(Version::current()),
+        }
+    }
+}
+
+impl ModelAssembler {
+    ///
+    /// Create a new, empty, assembler.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Read a source using `reader` and merge its shapes and metadata into the model being
+    /// assembled. Returns an error if this source's `Version` disagrees with a previously merged
+    /// source, or if it redefines an already-merged absolute shape ID with a differing body.
+    ///
+    pub fn add_source(
+        &mut self,
+        reader: &mut impl crate::io::ModelReader,
+        source: &mut impl Read,
+    ) -> Result<&mut Self> {
+        let next = reader.read(source)?;
+        self.merge(next)
+    }
+
+    ///
+    /// Merge an already-parsed [Model](../../model/struct.Model.html) into the model being
+    /// assembled.
+    ///
+    pub fn merge(&mut self, next: Model) -> Result<&mut Self> {
+        match &self.version {
+            None => self.version = Some(next.smithy_version().clone()),
+            Some(version) => {
+                if version != next.smithy_version() {
+                    return Err(ErrorKind::AssemblerVersionMismatch(
+                        version.to_string(),
+                        next.smithy_version().to_string(),
+                    )
+                    .into());
+                }
+            }
+        }
+
+        for shape in next.shapes() {
+            if let Some(existing) = self.model.shape(shape.id()) {
+                if existing != shape {
+                    return Err(ErrorKind::AssemblerShapeConflict(shape.id().to_string()).into());
+                }
+                continue;
+            }
+            self.model.add_shape(shape.clone());
+        }
+
+        for (key, value) in next.metadata() {
+            self.merge_metadata(key.clone(), value.clone())?;
+        }
+
+        Ok(self)
+    }
+
+    ///
+    /// Consume the assembler and return the merged model, having confirmed all shape references
+    /// — trait IDs, member targets, operation input/output/errors, resource identifiers and
+    /// lifecycle bindings, and service operations/resources — are absolute.
+    ///
+    pub fn assemble(self) -> Result<Model> {
+        for shape in self.model.shapes() {
+            for a_trait in shape.traits() {
+                self.check_reference(a_trait.id())?;
+            }
+            for target in referenced_shape_ids(shape.body()) {
+                self.check_reference(target)?;
+            }
+        }
+
+        let version = self.version.unwrap_or_else(Version::current);
+        let mut model = Model::new(version);
+        for (key, value) in self.model.metadata() {
+            model.add_metadata(key.clone(), value.clone());
+        }
+        for shape in self.model.shapes() {
+            model.add_shape(shape.clone());
+        }
+        Ok(model)
+    }
+
+    fn check_reference(&self, id: &ShapeID) -> Result<()> {
+        if id.namespace().is_none() {
+            return Err(ErrorKind::AssemblerUnresolvedReference(id.to_string()).into());
+        }
+        Ok(())
+    }
+
+    fn merge_metadata(&mut self, key: String, value: NodeValue) -> Result<()> {
+        if let Some((_, existing_value)) = self.model.metadata().find(|(k, _)| *k == &key) {
+            match (existing_value, &value) {
+                (NodeValue::Array(existing_items), NodeValue::Array(new_items)) => {
+                    let mut merged = existing_items.clone();
+                    merged.extend(new_items.clone());
+                    self.model.add_metadata(key, NodeValue::Array(merged));
+                    return Ok(());
+                }
+                (existing_value, value) if existing_value != value => {
+                    return Err(ErrorKind::AssemblerMetadataConflict(key).into());
+                }
+                _ => return Ok(()),
+            }
+        }
+        self.model.add_metadata(key, value);
+        Ok(())
+    }
+}
+
+///
+/// Every shape ID referenced from a shape's body: member targets, operation input/output/errors,
+/// resource identifiers and lifecycle bindings, and service operations/resources.
+///
+fn referenced_shape_ids(kind: &ShapeKind) -> Vec<&ShapeID> {
+    match kind {
+        ShapeKind::List(v) => vec![v.member().target()],
+        ShapeKind::Set(v) => vec![v.member().target()],
+        ShapeKind::Map(v) => vec![v.key().target(), v.value().target()],
+        ShapeKind::Structure(v) | ShapeKind::Union(v) => {
+            v.members().map(|m| m.target()).collect()
+        }
+        ShapeKind::Service(v) => v.operations().chain(v.resources()).collect(),
+        ShapeKind::Operation(v) => v
+            .input()
+            .into_iter()
+            .chain(v.output())
+            .chain(v.errors())
+            .collect(),
+        ShapeKind::Resource(v) => v
+            .identifiers()
+            .map(|(_, target)| target)
+            .chain([v.create(), v.put(), v.read(), v.update(), v.delete(), v.list()].into_iter().flatten())
+            .chain(v.operations())
+            .chain(v.collection_operations())
+            .chain(v.resources())
+            .collect(),
+        ShapeKind::Simple(_) | ShapeKind::Unresolved => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::shapes::{MemberShape, Operation, ShapeKind, StructureOrUnion, TopLevelShape};
+    use crate::model::NamespaceID;
+
+    fn model_with_one_shape(version: Version, name: &str) -> Model {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let mut model = Model::new(version);
+        model.add_shape(TopLevelShape::new(
+            namespace.make_shape(name.parse().unwrap()),
+            ShapeKind::Operation(Operation::default()),
+        ));
+        model
+    }
+
+    #[test]
+    fn merging_sources_with_different_versions_is_an_error() {
+        let mut assembler = ModelAssembler::new();
+        assembler
+            .merge(model_with_one_shape(Version::V10, "One"))
+            .unwrap();
+
+        let result = assembler.merge(model_with_one_shape(Version::V20, "Two"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn assembled_model_keeps_the_merged_sources_version_not_the_crate_default() {
+        let mut assembler = ModelAssembler::new();
+        assembler
+            .merge(model_with_one_shape(Version::V10, "One"))
+            .unwrap();
+
+        let model = assembler.assemble().unwrap();
+        assert_eq!(model.smithy_version(), &Version::V10);
+    }
+
+    #[test]
+    fn array_valued_metadata_concatenates_across_sources() {
+        let mut first = model_with_one_shape(Version::V10, "One");
+        first.add_metadata(
+            "tags".to_string(),
+            NodeValue::Array(vec![NodeValue::String("a".to_string())]),
+        );
+        let mut second = model_with_one_shape(Version::V10, "Two");
+        second.add_metadata(
+            "tags".to_string(),
+            NodeValue::Array(vec![NodeValue::String("b".to_string())]),
+        );
+
+        let mut assembler = ModelAssembler::new();
+        assembler.merge(first).unwrap();
+        assembler.merge(second).unwrap();
+        let model = assembler.assemble().unwrap();
+
+        let (_, tags) = model.metadata().find(|(k, _)| *k == "tags").unwrap();
+        assert_eq!(
+            tags,
+            &NodeValue::Array(vec![
+                NodeValue::String("a".to_string()),
+                NodeValue::String("b".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn a_relative_member_target_is_an_unresolved_reference() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let shape_id = namespace.make_shape("Thing".parse().unwrap());
+
+        let mut body = StructureOrUnion::new();
+        let relative_target: ShapeID = "Date".parse().unwrap();
+        let _ = body.add_a_member(MemberShape::new(
+            shape_id.make_member("date".parse().unwrap()),
+            relative_target,
+        ));
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(TopLevelShape::new(shape_id, ShapeKind::Structure(body)));
+
+        let mut assembler = ModelAssembler::new();
+        assembler.merge(model).unwrap();
+
+        assert!(assembler.assemble().is_err());
+    }
+}
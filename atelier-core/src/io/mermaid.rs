@@ -0,0 +1,309 @@
+/*!
+A writer that renders a [Model](../../model/struct.Model.html) as a [Mermaid](https://mermaid.js.org/)
+`classDiagram`, so models can be embedded directly in Markdown/GitHub docs. This follows the same
+structural mapping as the [`plant_uml`](../plant_uml/index.html) writer, but targets Mermaid's
+syntax instead of PlantUML's.
+
+Services, resources, structures and unions become classes; service/resource lifecycle and regular
+operations become methods named for the lifecycle (`create`/`read`/`update`/`delete`/`list`/`put`)
+or the operation's own shape name; and references between shapes become Mermaid relationship edges
+(`..>` for a plain reference, `o--` for aggregation of an owned resource/operation).
+*/
+
+use crate::error::Result;
+use crate::io::ModelWriter;
+use crate::model::shapes::{HasTraits, ShapeKind, TopLevelShape};
+use crate::model::{HasIdentity, Model, ShapeID};
+use std::collections::HashSet;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Write a [Model](../../model/struct.Model.html) out as a Mermaid `classDiagram`.
+///
+#[allow(missing_debug_implementations)]
+pub struct MermaidWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for MermaidWriter {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl<'a> ModelWriter<'a> for MermaidWriter {
+    const REPRESENTATION: &'static str = "Mermaid";
+
+    fn write(&mut self, w: &mut impl Write, model: &'a Model) -> Result<()> {
+        let errors = self.collect_errors(model);
+
+        writeln!(w, "classDiagram")?;
+
+        for shape in model.shapes() {
+            self.class(w, model, shape, &errors)?;
+        }
+        for shape in model.shapes() {
+            self.relationships(w, model, shape)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> MermaidWriter {
+    fn collect_errors(&self, model: &'a Model) -> HashSet<ShapeID> {
+        let mut errors = HashSet::new();
+        for shape in model.shapes() {
+            if let ShapeKind::Operation(op) = shape.body() {
+                for error in op.errors() {
+                    let _ = errors.insert(error.clone());
+                }
+            }
+        }
+        errors
+    }
+
+    fn class_name(&self, id: &ShapeID) -> String {
+        id.shape_name().to_string()
+    }
+
+    ///
+    /// Render an operation reference as a method signature carrying its input/output, e.g.
+    /// `+GetMessage(GetMessageInput): GetMessageOutput`, falling back to a bare `+name()` when the
+    /// referenced shape isn't in this model or isn't an operation.
+    ///
+    fn method_signature(&self, model: &'a Model, op: &ShapeID) -> String {
+        let name = self.class_name(op);
+        match model.shape(op).map(|shape| shape.body()) {
+            Some(ShapeKind::Operation(v)) => format!(
+                "+{}({}){}",
+                name,
+                v.input().map(|i| self.class_name(i)).unwrap_or_default(),
+                v.output()
+                    .map(|o| format!(": {}", self.class_name(o)))
+                    .unwrap_or_default()
+            ),
+            _ => format!("+{}()", name),
+        }
+    }
+
+    ///
+    /// Render a resource lifecycle binding as a method labeled by its lifecycle name instead of
+    /// the operation's own name, e.g. `+read(GetMessageInput): GetMessageOutput`.
+    ///
+    fn lifecycle_signature(&self, model: &'a Model, lifecycle: &str, op: &ShapeID) -> String {
+        let name = format!("+{}(", self.class_name(op));
+        self.method_signature(model, op)
+            .replacen(&name, &format!("+{}(", lifecycle), 1)
+    }
+
+    fn class(
+        &self,
+        w: &mut impl Write,
+        model: &'a Model,
+        shape: &'a TopLevelShape,
+        errors: &HashSet<ShapeID>,
+    ) -> Result<()> {
+        let name = self.class_name(shape.id());
+        match shape.body() {
+            ShapeKind::Service(v) => {
+                writeln!(w, "class {} {{", name)?;
+                writeln!(w, "  <<service>>")?;
+                writeln!(w, "  +version: \"{}\"", v.version())?;
+                for op in v.operations() {
+                    writeln!(w, "  {}", self.method_signature(model, op))?;
+                }
+                writeln!(w, "}}")?;
+            }
+            ShapeKind::Resource(v) => {
+                writeln!(w, "class {} {{", name)?;
+                writeln!(w, "  <<resource>>")?;
+                for (id, target) in v.identifiers() {
+                    writeln!(w, "  +{}: {}", id, target)?;
+                }
+                if let Some(op) = v.create() {
+                    writeln!(w, "  {}", self.lifecycle_signature(model, "create", op))?;
+                }
+                if let Some(op) = v.put() {
+                    writeln!(w, "  {}", self.lifecycle_signature(model, "put", op))?;
+                }
+                if let Some(op) = v.read() {
+                    writeln!(w, "  {}", self.lifecycle_signature(model, "read", op))?;
+                }
+                if let Some(op) = v.update() {
+                    writeln!(w, "  {}", self.lifecycle_signature(model, "update", op))?;
+                }
+                if let Some(op) = v.delete() {
+                    writeln!(w, "  {}", self.lifecycle_signature(model, "delete", op))?;
+                }
+                if let Some(op) = v.list() {
+                    writeln!(w, "  {}", self.lifecycle_signature(model, "list", op))?;
+                }
+                for op in v.operations().chain(v.collection_operations()) {
+                    writeln!(w, "  {}", self.method_signature(model, op))?;
+                }
+                writeln!(w, "}}")?;
+            }
+            ShapeKind::Structure(v) | ShapeKind::Union(v) => {
+                writeln!(w, "class {} {{", name)?;
+                if errors.contains(shape.id()) {
+                    writeln!(w, "  <<error>>")?;
+                }
+                for member in v.members() {
+                    writeln!(w, "  +{}: {}", member.id().member_name().unwrap_or_default(), member.target().shape_name())?;
+                }
+                writeln!(w, "}}")?;
+            }
+            ShapeKind::Operation(v) => {
+                writeln!(w, "class {} {{", name)?;
+                writeln!(w, "  <<operation>>")?;
+                if let Some(input) = v.input() {
+                    writeln!(w, "  +input: {}", input.shape_name())?;
+                }
+                if let Some(output) = v.output() {
+                    writeln!(w, "  +output: {}", output.shape_name())?;
+                }
+                writeln!(w, "}}")?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn relationships(&self, w: &mut impl Write, model: &'a Model, shape: &'a TopLevelShape) -> Result<()> {
+        let name = self.class_name(shape.id());
+        match shape.body() {
+            ShapeKind::Service(v) => {
+                for res in v.resources() {
+                    writeln!(w, "{} o-- {}", name, self.class_name(res))?;
+                }
+                for op in v.operations() {
+                    writeln!(w, "{} o-- {}", name, self.class_name(op))?;
+                    // Surface the errors an owned operation can raise directly on the
+                    // service, so callers can see a service's full error surface at a glance.
+                    if let Some(ShapeKind::Operation(operation)) =
+                        model.shape(op).map(|s| s.body())
+                    {
+                        for error in operation.errors() {
+                            writeln!(w, "{} ..> {}", name, self.class_name(error))?;
+                        }
+                    }
+                }
+            }
+            ShapeKind::Resource(v) => {
+                for res in v.resources() {
+                    writeln!(w, "{} o-- {}", name, self.class_name(res))?;
+                }
+            }
+            ShapeKind::Operation(v) => {
+                if let Some(input) = v.input() {
+                    writeln!(w, "{} ..> {}", name, self.class_name(input))?;
+                }
+                if let Some(output) = v.output() {
+                    writeln!(w, "{} ..> {}", name, self.class_name(output))?;
+                }
+                for error in v.errors() {
+                    writeln!(w, "{} ..> {}", name, self.class_name(error))?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::write_model_to_string;
+    use crate::model::shapes::{MemberShape, Operation, Resource, Service, StructureOrUnion};
+    use crate::model::NamespaceID;
+    use crate::Version;
+
+    #[test]
+    fn renders_classes_and_a_service_to_resource_relationship() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let prelude: NamespaceID = "smithy.api".parse().unwrap();
+        let string_target = prelude.make_shape("String".parse().unwrap());
+
+        let widget_id = namespace.make_shape("Widget".parse().unwrap());
+        let mut widget_body = StructureOrUnion::new();
+        let _ = widget_body.add_a_member(MemberShape::new(
+            widget_id.make_member("name".parse().unwrap()),
+            string_target,
+        ));
+        let widget = TopLevelShape::new(widget_id, ShapeKind::Structure(widget_body));
+
+        let resource_id = namespace.make_shape("Widgets".parse().unwrap());
+        let resource = TopLevelShape::new(resource_id.clone(), ShapeKind::Resource(Resource::default()));
+
+        let mut service_body = Service::new("2020-01-01");
+        service_body.add_resource(resource_id);
+        let service = TopLevelShape::new(
+            namespace.make_shape("WidgetService".parse().unwrap()),
+            ShapeKind::Service(service_body),
+        );
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(widget);
+        model.add_shape(resource);
+        model.add_shape(service);
+
+        let diagram = write_model_to_string(&mut MermaidWriter::default(), &model).unwrap();
+
+        assert!(diagram.starts_with("classDiagram\n"));
+        assert!(diagram.contains("class Widget {"));
+        assert!(diagram.contains("+name: String"));
+        assert!(diagram.contains("class Widgets {"));
+        assert!(diagram.contains("<<resource>>"));
+        assert!(diagram.contains("WidgetService o-- Widgets"));
+    }
+
+    #[test]
+    fn renders_operation_method_signatures_and_a_direct_service_to_error_edge() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+
+        let input_id = namespace.make_shape("GetMessageInput".parse().unwrap());
+        let input = TopLevelShape::new(input_id.clone(), ShapeKind::Structure(StructureOrUnion::new()));
+
+        let output_id = namespace.make_shape("GetMessageOutput".parse().unwrap());
+        let output = TopLevelShape::new(output_id.clone(), ShapeKind::Structure(StructureOrUnion::new()));
+
+        let error_id = namespace.make_shape("NotFoundError".parse().unwrap());
+        let error = TopLevelShape::new(error_id.clone(), ShapeKind::Structure(StructureOrUnion::new()));
+
+        let mut op = Operation::default();
+        op.set_input(input_id);
+        op.set_output(output_id);
+        op.add_error(error_id);
+        let operation = TopLevelShape::new(
+            namespace.make_shape("GetMessage".parse().unwrap()),
+            ShapeKind::Operation(op),
+        );
+
+        let mut service_body = Service::new("2020-01-01");
+        service_body.add_operation(operation.id().clone());
+        let service = TopLevelShape::new(
+            namespace.make_shape("WidgetService".parse().unwrap()),
+            ShapeKind::Service(service_body),
+        );
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(input);
+        model.add_shape(output);
+        model.add_shape(error);
+        model.add_shape(operation);
+        model.add_shape(service);
+
+        let diagram = write_model_to_string(&mut MermaidWriter::default(), &model).unwrap();
+
+        assert!(diagram.contains("+GetMessage(GetMessageInput): GetMessageOutput"));
+        assert!(diagram.contains("WidgetService ..> NotFoundError"));
+    }
+}
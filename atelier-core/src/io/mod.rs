@@ -7,6 +7,9 @@ by default.
 
 * **debug**; uses the `Debug` implementation of Model to write out the internal structure.
 * **uml**; uses [PlantUML](https://plantuml.com/) to generate diagrams of a model structure.
+* **mermaid**; uses [Mermaid](https://mermaid.js.org/) `classDiagram` syntax to generate diagrams
+  of a model structure, for embedding directly in Markdown.
+* **rdf**; serializes a model as an RDF graph encoded using JSON-LD.
 
 # Example Model Writer
 
@@ -102,11 +105,19 @@ pub fn write_model_to_string<'a>(w: &mut impl ModelWriter<'a>, model: &'a Model)
 // Modules
 // ------------------------------------------------------------------------------------------------
 
+pub mod assembler;
+
 #[cfg(feature = "debug")]
 pub mod debug;
 
 #[cfg(feature = "uml")]
 pub mod plant_uml;
 
+#[cfg(feature = "mermaid")]
+pub mod mermaid;
+
+#[cfg(feature = "rdf")]
+pub mod rdf;
+
 #[cfg(feature = "tree")]
 pub mod tree;
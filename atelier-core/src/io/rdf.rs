@@ -0,0 +1,323 @@
+/*!
+An RDF writer that serializes a [Model](../../model/struct.Model.html) as an RDF graph encoded
+using [JSON-LD](https://json-ld.org/). Each shape becomes a subject IRI, `rdf:type` captures the
+shape's `ShapeKind`, and every applied trait becomes a predicate/object edge. This allows models to
+be loaded into triple stores and other linked-data tooling.
+
+To keep output stable and diff-friendly, subjects and their predicate sets are accumulated in
+insertion-ordered maps rather than hash-ordered containers, grouped under a default graph plus one
+named graph per namespace.
+*/
+
+use crate::error::{Result, ResultExt};
+use crate::io::ModelWriter;
+use crate::model::shapes::{HasTraits, Shape, ShapeKind, TopLevelShape};
+use crate::model::values::{Number, Value as NodeValue};
+use crate::model::{HasIdentity, Model, ShapeID};
+use indexmap::{IndexMap, IndexSet};
+use serde_json::{json, Map, Value};
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+const RDF_TYPE: &str = "rdf:type";
+const DEFAULT_GRAPH: &str = "@default";
+
+///
+/// A single RDF triple's object, either a literal value or a reference to another subject.
+///
+#[derive(Clone, Debug)]
+enum RdfObject {
+    Node(String),
+    Literal(Value),
+}
+
+///
+/// Write a [Model](../../model/struct.Model.html) out as an RDF graph serialized in JSON-LD.
+///
+#[allow(missing_debug_implementations)]
+pub struct RdfWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for RdfWriter {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl<'a> ModelWriter<'a> for RdfWriter {
+    const REPRESENTATION: &'static str = "RDF/JSON-LD";
+
+    fn write(&mut self, w: &mut impl Write, model: &'a Model) -> Result<()> {
+        let mut graphs: IndexMap<String, IndexMap<String, IndexSet<(String, RdfObject)>>> =
+            IndexMap::new();
+
+        for shape in model.shapes() {
+            self.shape(&mut graphs, shape);
+        }
+
+        let mut named_graphs: Vec<Value> = Vec::new();
+        for (graph_name, subjects) in graphs {
+            let mut graph_nodes: Vec<Value> = Vec::new();
+            for (subject, predicates) in subjects {
+                let mut node: Map<String, Value> = Default::default();
+                let _ = node.insert("@id".to_string(), Value::String(subject));
+                let mut by_predicate: IndexMap<String, Vec<Value>> = IndexMap::new();
+                for (predicate, object) in predicates {
+                    let object = match object {
+                        RdfObject::Node(id) => json!({ "@id": id }),
+                        RdfObject::Literal(v) => v,
+                    };
+                    by_predicate.entry(predicate).or_default().push(object);
+                }
+                for (predicate, mut objects) in by_predicate {
+                    let value = if objects.len() == 1 {
+                        objects.remove(0)
+                    } else {
+                        Value::Array(objects)
+                    };
+                    let _ = node.insert(predicate, value);
+                }
+                graph_nodes.push(Value::Object(node));
+            }
+            if graph_name == DEFAULT_GRAPH {
+                named_graphs.push(json!({ "@graph": graph_nodes }));
+            } else {
+                named_graphs.push(json!({ "@id": graph_name, "@graph": graph_nodes }));
+            }
+        }
+
+        let document = json!({ "@graph": named_graphs });
+        serde_json::to_writer_pretty(w, &document)
+            .chain_err(|| "failed to serialize RDF/JSON-LD graph".to_string())
+    }
+}
+
+impl RdfWriter {
+    fn shape(
+        &self,
+        graphs: &mut IndexMap<String, IndexMap<String, IndexSet<(String, RdfObject)>>>,
+        shape: &TopLevelShape,
+    ) {
+        let graph_name = shape
+            .id()
+            .namespace()
+            .map(|ns| ns.to_string())
+            .unwrap_or_else(|| DEFAULT_GRAPH.to_string());
+        let subject = shape.id().to_string();
+
+        self.add_triple(
+            graphs,
+            &graph_name,
+            &subject,
+            RDF_TYPE.to_string(),
+            RdfObject::Literal(Value::String(shape_kind_name(shape.body()).to_string())),
+        );
+
+        for a_trait in shape.traits() {
+            let predicate = a_trait.id().to_string();
+            let object = match a_trait.value() {
+                None => RdfObject::Literal(Value::Bool(true)),
+                Some(value) => RdfObject::Literal(node_value_to_literal(value)),
+            };
+            self.add_triple(graphs, &graph_name, &subject, predicate, object);
+        }
+
+        match shape.body() {
+            ShapeKind::List(v) => self.add_ref(graphs, &graph_name, &subject, "smithy:member", v.member().target()),
+            ShapeKind::Set(v) => self.add_ref(graphs, &graph_name, &subject, "smithy:member", v.member().target()),
+            ShapeKind::Map(v) => {
+                self.add_ref(graphs, &graph_name, &subject, "smithy:key", v.key().target());
+                self.add_ref(graphs, &graph_name, &subject, "smithy:value", v.value().target());
+            }
+            ShapeKind::Structure(v) | ShapeKind::Union(v) => {
+                for member in v.members() {
+                    // A member has no identity of its own in the RDF graph, so it is modeled
+                    // as a blank node carrying a `smithy:target` edge to the referenced shape.
+                    // Keyed by the full member id (owning shape + member name) so that members
+                    // sharing a name across different shapes don't collide onto one blank node.
+                    let blank_node = format!("_:{}", member.id());
+                    self.add_triple(
+                        graphs,
+                        &graph_name,
+                        &subject,
+                        "smithy:member".to_string(),
+                        RdfObject::Node(blank_node.clone()),
+                    );
+                    self.add_ref(graphs, &graph_name, &blank_node, "smithy:target", member.target());
+                }
+            }
+            ShapeKind::Service(v) => {
+                for op in v.operations() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:operation", op);
+                }
+                for res in v.resources() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:resource", res);
+                }
+            }
+            ShapeKind::Operation(v) => {
+                if let Some(id) = v.input() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:input", id);
+                }
+                if let Some(id) = v.output() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:output", id);
+                }
+                for err in v.errors() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:error", err);
+                }
+            }
+            ShapeKind::Resource(v) => {
+                if let Some(id) = v.create() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:create", id);
+                }
+                if let Some(id) = v.put() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:put", id);
+                }
+                if let Some(id) = v.read() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:read", id);
+                }
+                if let Some(id) = v.update() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:update", id);
+                }
+                if let Some(id) = v.delete() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:delete", id);
+                }
+                if let Some(id) = v.list() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:list", id);
+                }
+                for res in v.resources() {
+                    self.add_ref(graphs, &graph_name, &subject, "smithy:resource", res);
+                }
+            }
+            ShapeKind::Simple(_) | ShapeKind::Unresolved => {}
+        }
+    }
+
+    fn add_ref(
+        &self,
+        graphs: &mut IndexMap<String, IndexMap<String, IndexSet<(String, RdfObject)>>>,
+        graph_name: &str,
+        subject: &str,
+        predicate: &str,
+        target: &ShapeID,
+    ) {
+        self.add_triple(
+            graphs,
+            graph_name,
+            subject,
+            predicate.to_string(),
+            RdfObject::Node(target.to_string()),
+        );
+    }
+
+    fn add_triple(
+        &self,
+        graphs: &mut IndexMap<String, IndexMap<String, IndexSet<(String, RdfObject)>>>,
+        graph_name: &str,
+        subject: &str,
+        predicate: String,
+        object: RdfObject,
+    ) {
+        let _ = graphs
+            .entry(graph_name.to_string())
+            .or_insert_with(IndexMap::new)
+            .entry(subject.to_string())
+            .or_insert_with(IndexSet::new)
+            .insert((predicate, object));
+    }
+}
+
+impl std::cmp::Eq for RdfObject {}
+
+impl std::cmp::PartialEq for RdfObject {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RdfObject::Node(a), RdfObject::Node(b)) => a == b,
+            (RdfObject::Literal(a), RdfObject::Literal(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl std::hash::Hash for RdfObject {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            RdfObject::Node(v) => v.hash(state),
+            RdfObject::Literal(v) => v.to_string().hash(state),
+        }
+    }
+}
+
+fn shape_kind_name(kind: &ShapeKind) -> &'static str {
+    match kind {
+        ShapeKind::Simple(_) => "smithy:Simple",
+        ShapeKind::List(_) => "smithy:List",
+        ShapeKind::Set(_) => "smithy:Set",
+        ShapeKind::Map(_) => "smithy:Map",
+        ShapeKind::Structure(_) => "smithy:Structure",
+        ShapeKind::Union(_) => "smithy:Union",
+        ShapeKind::Service(_) => "smithy:Service",
+        ShapeKind::Operation(_) => "smithy:Operation",
+        ShapeKind::Resource(_) => "smithy:Resource",
+        ShapeKind::Unresolved => "smithy:Apply",
+    }
+}
+
+fn node_value_to_literal(value: &NodeValue) -> Value {
+    match value {
+        NodeValue::None => Value::Null,
+        NodeValue::Array(v) => Value::Array(v.iter().map(node_value_to_literal).collect()),
+        NodeValue::Object(v) => {
+            let mut object: Map<String, Value> = Default::default();
+            for (k, v) in v {
+                let _ = object.insert(k.clone(), node_value_to_literal(v));
+            }
+            Value::Object(object)
+        }
+        NodeValue::Number(Number::Integer(v)) => Value::Number((*v).into()),
+        NodeValue::Number(Number::Float(v)) => {
+            Value::Number(serde_json::Number::from_f64(*v).unwrap())
+        }
+        NodeValue::Boolean(v) => Value::Bool(*v),
+        NodeValue::String(v) => Value::String(v.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::write_model_to_string;
+    use crate::model::shapes::{MemberShape, StructureOrUnion};
+    use crate::model::{HasIdentity, Model, NamespaceID};
+    use crate::Version;
+
+    #[test]
+    fn members_sharing_a_name_get_distinct_blank_nodes() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let prelude: NamespaceID = "smithy.api".parse().unwrap();
+        let string_target = prelude.make_shape("String".parse().unwrap());
+
+        let mut make_struct_with_id_member = |name: &str| {
+            let shape_id = namespace.make_shape(name.parse().unwrap());
+            let mut body = StructureOrUnion::new();
+            let member = MemberShape::new(shape_id.make_member("id".parse().unwrap()), string_target.clone());
+            let _ = body.add_a_member(member);
+            TopLevelShape::new(shape_id, ShapeKind::Structure(body))
+        };
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(make_struct_with_id_member("Widget"));
+        model.add_shape(make_struct_with_id_member("Gadget"));
+
+        let json = write_model_to_string(&mut RdfWriter::default(), &model).unwrap();
+
+        assert!(json.contains("example.motd#Widget$id"));
+        assert!(json.contains("example.motd#Gadget$id"));
+        assert!(!json.contains("\"_:id\""));
+    }
+}
@@ -302,8 +302,25 @@ use std::str::FromStr;
 ///
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
 pub enum Version {
-    /// Version 1.0 (initial, and current)
+    /// Version 1.0 (initial)
     V10,
+    /// Version 2.0 (current); adds `enum`/`intEnum` as first-class shape types, mixins, and
+    /// elided member targets among other constructs.
+    V20,
+}
+
+///
+/// A version-gated Smithy construct that an [`action`](action/index.html) validator can check for
+/// before accepting a model element, via [`Version::supports`](enum.Version.html#method.supports).
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feature {
+    /// `enum`/`intEnum` as proper shape kinds, rather than the `enum` trait on a `string` shape.
+    EnumShapes,
+    /// Mixins (`with` clause on shape definitions).
+    Mixins,
+    /// Elided member targets (`$member`) that inherit their target from a mixin.
+    ElidedMemberTargets,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -318,7 +335,10 @@ impl Default for Version {
 
 impl Display for Version {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "1.0")
+        match self {
+            Self::V10 => write!(f, "1.0"),
+            Self::V20 => write!(f, "2.0"),
+        }
     }
 }
 
@@ -326,10 +346,10 @@ impl FromStr for Version {
     type Err = error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "1.0" {
-            Ok(Self::V10)
-        } else {
-            Err(error::ErrorKind::InvalidVersionNumber(s.to_string()).into())
+        match s {
+            "1.0" => Ok(Self::V10),
+            "2.0" => Ok(Self::V20),
+            _ => Err(error::ErrorKind::InvalidVersionNumber(s.to_string()).into()),
         }
     }
 }
@@ -339,7 +359,19 @@ impl Version {
     /// Returns the most current version of the Smithy specification.
     ///
     pub fn current() -> Self {
-        Self::V10
+        Self::V20
+    }
+
+    ///
+    /// Returns `true` if this version of the specification supports the given `feature`, so that a
+    /// 2.0-only construct appearing in a 1.0 model can be reported as a validation error rather
+    /// than a parse-time panic.
+    ///
+    pub fn supports(&self, feature: Feature) -> bool {
+        match (self, feature) {
+            (Self::V20, _) => true,
+            (Self::V10, _) => false,
+        }
     }
 }
 
@@ -364,3 +396,31 @@ pub mod model;
 pub mod prelude;
 
 pub mod syntax;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_version_strings() {
+        assert_eq!(Version::from_str("1.0").unwrap(), Version::V10);
+        assert_eq!(Version::from_str("2.0").unwrap(), Version::V20);
+        assert!(Version::from_str("3.0").is_err());
+    }
+
+    #[test]
+    fn only_v20_supports_2_0_only_features() {
+        assert!(!Version::V10.supports(Feature::EnumShapes));
+        assert!(!Version::V10.supports(Feature::Mixins));
+        assert!(!Version::V10.supports(Feature::ElidedMemberTargets));
+        assert!(Version::V20.supports(Feature::EnumShapes));
+        assert!(Version::V20.supports(Feature::Mixins));
+        assert!(Version::V20.supports(Feature::ElidedMemberTargets));
+    }
+
+    #[test]
+    fn current_version_is_2_0() {
+        assert_eq!(Version::current(), Version::V20);
+        assert_eq!(Version::default(), Version::V20);
+    }
+}
@@ -0,0 +1,89 @@
+/*!
+A typed view of the `paginated` trait's structured value (`inputToken`, `outputToken`, `items`,
+`pageSize`), plus the inheritance rule by which a service's `paginated` trait supplies defaults for
+any operation that does not override them.
+*/
+
+use crate::model::shapes::{HasTraits, Shape};
+use crate::model::values::Value as NodeValue;
+use crate::prelude::TRAIT_PAGINATED;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The structured members of an applied `paginated` trait. Each member is optional in the trait
+/// value itself; a member left `None` here is filled in, operation by operation, from the owning
+/// service's own `paginated` trait by [`Paginated::inherit`](#method.inherit).
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Paginated {
+    /// The input member that carries the continuation token for the next page.
+    pub input_token: Option<String>,
+    /// The output member that carries the continuation token for the next page.
+    pub output_token: Option<String>,
+    /// The output member referencing the list/map of returned items.
+    pub items: Option<String>,
+    /// The input member controlling the maximum number of items per page.
+    pub page_size: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Paginated {
+    ///
+    /// Read the `paginated` trait, if any, applied to `shape`.
+    ///
+    pub fn from_shape(shape: &impl Shape) -> Option<Self> {
+        shape
+            .traits()
+            .iter()
+            .find(|t| t.id().shape_name().to_string() == TRAIT_PAGINATED)
+            .map(|t| Self::from_value(t.value()))
+    }
+
+    fn from_value(value: Option<&NodeValue>) -> Self {
+        let object = match value {
+            Some(NodeValue::Object(object)) => object,
+            _ => return Self::default(),
+        };
+        Self {
+            input_token: string_member(object, "inputToken"),
+            output_token: string_member(object, "outputToken"),
+            items: string_member(object, "items"),
+            page_size: string_member(object, "pageSize"),
+        }
+    }
+
+    ///
+    /// Fill in any member left unset here from `service_default`, per Smithy's trait-inheritance
+    /// rule: an operation's own `paginated` values always win, the service's are the fallback.
+    ///
+    pub fn inherit(&self, service_default: &Paginated) -> Self {
+        Self {
+            input_token: self
+                .input_token
+                .clone()
+                .or_else(|| service_default.input_token.clone()),
+            output_token: self
+                .output_token
+                .clone()
+                .or_else(|| service_default.output_token.clone()),
+            items: self.items.clone().or_else(|| service_default.items.clone()),
+            page_size: self
+                .page_size
+                .clone()
+                .or_else(|| service_default.page_size.clone()),
+        }
+    }
+}
+
+fn string_member(object: &std::collections::HashMap<String, NodeValue>, key: &str) -> Option<String> {
+    match object.get(key) {
+        Some(NodeValue::String(v)) => Some(v.clone()),
+        _ => None,
+    }
+}
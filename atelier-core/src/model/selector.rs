@@ -0,0 +1,367 @@
+/*!
+A parser and evaluator for Smithy's shape [selector](https://smithy.io/2.0/spec/selectors.html)
+expression language, used by [`action`](../../action/index.html) linters/validators/transforms to
+scope themselves to a subset of a model's shape graph instead of hand-rolling a walk.
+
+The supported grammar is a useful subset of the full language:
+
+* a shape-type filter: `structure`, `operation`, `service`, `resource`, `list`, `set`, `map`,
+  `union`, `simple`, or `*` for any shape,
+* an optional bracketed predicate testing for the presence of a trait, e.g. `[trait|required]` or
+  `[trait|length]`; trait values are not tested,
+* neighbor traversals chained with `>` (direct members/bindings) or `~>` (transitive reachability),
+  e.g. `service ~> operation` selects every operation reachable from any service.
+
+Evaluation walks a shape-graph adjacency representation — shape to referenced shapes via members,
+operation bindings, resource lifecycle, and applied-trait references — doing a BFS for `~>` and a
+single hop for `>`, guarding against cycles with a visited set.
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::shapes::{HasTraits, ShapeKind};
+use crate::model::{HasIdentity, Model, ShapeID};
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single shape-type filter term in a selector, e.g. `structure` or `*`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShapeTypeFilter {
+    /// Matches any shape.
+    Any,
+    /// Matches shapes of the given Smithy type name (`structure`, `operation`, ...).
+    Named(String),
+}
+
+///
+/// A predicate bracket attached to a selector term, e.g. `[trait|required]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Predicate {
+    /// The shape has a trait applied with the given (unqualified) name.
+    HasTrait(String),
+}
+
+///
+/// How one selector term is connected to the next.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Traversal {
+    /// `>`; direct members/bindings only.
+    Direct,
+    /// `~>`; transitive reachability (BFS).
+    Transitive,
+}
+
+///
+/// One term of a compiled selector: a shape-type filter, its predicates, and (if it is not the
+/// last term) how it connects to the next term. A term with no traversal is followed by a term
+/// that further filters the same shapes, as in `structure [trait|required]`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectorTerm {
+    filter: ShapeTypeFilter,
+    predicates: Vec<Predicate>,
+    traversal: Option<Traversal>,
+}
+
+///
+/// A compiled selector expression, ready to [`evaluate`](#method.evaluate) against a model.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+    terms: Vec<SelectorTerm>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FromStr for Selector {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut terms = Vec::new();
+        let mut chunks = s.split_whitespace().peekable();
+
+        while let Some(chunk) = chunks.next() {
+            if chunk == ">" || chunk == "~>" {
+                let traversal = if chunk == ">" {
+                    Traversal::Direct
+                } else {
+                    Traversal::Transitive
+                };
+                if let Some(last) = terms.last_mut() {
+                    let last: &mut SelectorTerm = last;
+                    last.traversal = Some(traversal);
+                } else {
+                    return Err(ErrorKind::InvalidSelectorExpression(s.to_string()).into());
+                }
+                continue;
+            }
+            terms.push(parse_term(chunk)?);
+        }
+
+        if terms.is_empty() {
+            return Err(ErrorKind::InvalidSelectorExpression(s.to_string()).into());
+        }
+
+        Ok(Self { terms })
+    }
+}
+
+impl Selector {
+    ///
+    /// Evaluate this selector against `model`, returning the set of matching top-level shape IDs.
+    /// Member shapes are not matched, so no member shape ID is ever returned.
+    ///
+    pub fn evaluate(&self, model: &Model) -> HashSet<ShapeID> {
+        let graph = ShapeGraph::build(model);
+
+        let mut current: HashSet<ShapeID> = model
+            .shapes()
+            .map(|s| s.id().clone())
+            .filter(|id| self.terms[0].matches(model, id))
+            .collect();
+
+        for window in self.terms.windows(2) {
+            let from_term = &window[0];
+            let to_term = &window[1];
+
+            let mut next = HashSet::new();
+            for id in &current {
+                match from_term.traversal {
+                    None => {
+                        if to_term.matches(model, id) {
+                            let _ = next.insert(id.clone());
+                        }
+                    }
+                    Some(Traversal::Direct) => {
+                        for neighbor in graph.neighbors(id) {
+                            if to_term.matches(model, neighbor) {
+                                let _ = next.insert(neighbor.clone());
+                            }
+                        }
+                    }
+                    Some(Traversal::Transitive) => {
+                        for neighbor in graph.reachable(id) {
+                            if to_term.matches(model, &neighbor) {
+                                let _ = next.insert(neighbor);
+                            }
+                        }
+                    }
+                }
+            }
+            current = next;
+        }
+
+        current
+    }
+}
+
+impl SelectorTerm {
+    fn matches(&self, model: &Model, id: &ShapeID) -> bool {
+        let shape = match model.shape(id) {
+            Some(shape) => shape,
+            None => return false,
+        };
+
+        let type_matches = match &self.filter {
+            ShapeTypeFilter::Any => true,
+            ShapeTypeFilter::Named(name) => shape_type_name(shape.body()) == name,
+        };
+        if !type_matches {
+            return false;
+        }
+
+        self.predicates.iter().all(|predicate| match predicate {
+            Predicate::HasTrait(name) => shape
+                .traits()
+                .iter()
+                .any(|t| &t.id().shape_name().to_string() == name),
+        })
+    }
+}
+
+///
+/// An adjacency representation of a model's shape graph: each shape ID maps to the shape IDs it
+/// references via members, operation input/output/errors, resource lifecycle bindings, and
+/// applied traits.
+///
+struct ShapeGraph {
+    edges: std::collections::HashMap<ShapeID, Vec<ShapeID>>,
+}
+
+impl ShapeGraph {
+    fn build(model: &Model) -> Self {
+        let mut edges: std::collections::HashMap<ShapeID, Vec<ShapeID>> = Default::default();
+
+        for shape in model.shapes() {
+            let mut targets = Vec::new();
+            for a_trait in shape.traits() {
+                targets.push(a_trait.id().clone());
+            }
+            match shape.body() {
+                ShapeKind::List(v) => targets.push(v.member().target().clone()),
+                ShapeKind::Set(v) => targets.push(v.member().target().clone()),
+                ShapeKind::Map(v) => {
+                    targets.push(v.key().target().clone());
+                    targets.push(v.value().target().clone());
+                }
+                ShapeKind::Structure(v) | ShapeKind::Union(v) => {
+                    for member in v.members() {
+                        targets.push(member.target().clone());
+                    }
+                }
+                ShapeKind::Service(v) => {
+                    targets.extend(v.operations().cloned());
+                    targets.extend(v.resources().cloned());
+                }
+                ShapeKind::Operation(v) => {
+                    targets.extend(v.input().cloned());
+                    targets.extend(v.output().cloned());
+                    targets.extend(v.errors().cloned());
+                }
+                ShapeKind::Resource(v) => {
+                    targets.extend(v.identifiers().map(|(_, target)| target.clone()));
+                    targets.extend(v.create().cloned());
+                    targets.extend(v.put().cloned());
+                    targets.extend(v.read().cloned());
+                    targets.extend(v.update().cloned());
+                    targets.extend(v.delete().cloned());
+                    targets.extend(v.list().cloned());
+                    targets.extend(v.operations().cloned());
+                    targets.extend(v.collection_operations().cloned());
+                    targets.extend(v.resources().cloned());
+                }
+                ShapeKind::Simple(_) | ShapeKind::Unresolved => {}
+            }
+            let _ = edges.insert(shape.id().clone(), targets);
+        }
+
+        Self { edges }
+    }
+
+    fn neighbors(&self, id: &ShapeID) -> impl Iterator<Item = &ShapeID> {
+        self.edges.get(id).into_iter().flatten()
+    }
+
+    fn reachable(&self, id: &ShapeID) -> HashSet<ShapeID> {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(id.clone());
+
+        while let Some(current) = queue.pop_front() {
+            for neighbor in self.neighbors(&current) {
+                if visited.insert(neighbor.clone()) {
+                    queue.push_back(neighbor.clone());
+                }
+            }
+        }
+
+        visited
+    }
+}
+
+fn parse_term(chunk: &str) -> Result<SelectorTerm> {
+    let (filter_part, predicate_part) = match chunk.find('[') {
+        Some(start) => {
+            let end = chunk
+                .rfind(']')
+                .ok_or_else(|| ErrorKind::InvalidSelectorExpression(chunk.to_string()))?;
+            (&chunk[..start], Some(&chunk[start + 1..end]))
+        }
+        None => (chunk, None),
+    };
+
+    let filter = if filter_part == "*" || filter_part.is_empty() {
+        ShapeTypeFilter::Any
+    } else {
+        ShapeTypeFilter::Named(filter_part.to_string())
+    };
+
+    let mut predicates = Vec::new();
+    if let Some(predicate) = predicate_part {
+        let mut parts = predicate.splitn(2, '|');
+        match (parts.next(), parts.next()) {
+            (Some("trait"), Some(name)) => predicates.push(Predicate::HasTrait(name.to_string())),
+            _ => return Err(ErrorKind::InvalidSelectorExpression(chunk.to_string()).into()),
+        }
+    }
+
+    Ok(SelectorTerm {
+        filter,
+        predicates,
+        traversal: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::shapes::{AppliedTrait, HasTraits, Simple, TopLevelShape};
+    use crate::model::NamespaceID;
+    use crate::Version;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_a_type_filter_with_a_trait_predicate() {
+        let selector = Selector::from_str("structure [trait|required]").unwrap();
+        assert_eq!(selector.terms.len(), 2);
+    }
+
+    #[test]
+    fn rejects_a_traversal_with_no_preceding_term() {
+        assert!(Selector::from_str("> structure").is_err());
+    }
+
+    #[test]
+    fn rejects_an_empty_expression() {
+        assert!(Selector::from_str("").is_err());
+    }
+
+    #[test]
+    fn evaluate_selects_shapes_of_the_requested_type() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let prelude: NamespaceID = "smithy.api".parse().unwrap();
+
+        let mut model = Model::new(Version::V10);
+        model.add_shape(TopLevelShape::new(
+            namespace.make_shape("Date".parse().unwrap()),
+            ShapeKind::Simple(Simple::String),
+        ));
+        let mut required_member = TopLevelShape::new(
+            namespace.make_shape("Count".parse().unwrap()),
+            ShapeKind::Simple(Simple::Integer),
+        );
+        required_member.apply_trait(AppliedTrait::new(
+            prelude.make_shape("required".parse().unwrap()),
+        ));
+        model.add_shape(required_member);
+
+        let selector = Selector::from_str("simple [trait|required]").unwrap();
+        let matches = selector.evaluate(&model);
+
+        assert_eq!(matches.len(), 1);
+        assert!(matches.contains(&namespace.make_shape("Count".parse().unwrap())));
+    }
+}
+
+fn shape_type_name(kind: &ShapeKind) -> String {
+    match kind {
+        ShapeKind::Simple(_) => "simple".to_string(),
+        ShapeKind::List(_) => "list".to_string(),
+        ShapeKind::Set(_) => "set".to_string(),
+        ShapeKind::Map(_) => "map".to_string(),
+        ShapeKind::Structure(_) => "structure".to_string(),
+        ShapeKind::Union(_) => "union".to_string(),
+        ShapeKind::Service(_) => "service".to_string(),
+        ShapeKind::Operation(_) => "operation".to_string(),
+        ShapeKind::Resource(_) => "resource".to_string(),
+        ShapeKind::Unresolved => "apply".to_string(),
+    }
+}
@@ -0,0 +1,365 @@
+use crate::syntax::*;
+use crate::FILE_EXTENSION;
+use atelier_core::error::{ErrorKind, Result as ModelResult, ResultExt};
+use atelier_core::io::ModelReader;
+use atelier_core::model::shapes::{
+    AppliedTrait, MemberShape, Operation, Resource, Service, ShapeKind, Simple, StructureOrUnion,
+    TopLevelShape,
+};
+use atelier_core::model::values::{Number, Value as NodeValue};
+use atelier_core::model::{HasIdentity, Model, ShapeID};
+use atelier_core::Version;
+use serde_json::{from_reader, Map, Value};
+use std::io::Read;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Read a [Model](../atelier_core/model/struct.Model.html) from the JSON AST representation. This
+/// is the inverse of [`JsonWriter`](struct.JsonWriter.html); it reverses every branch handled by
+/// `JsonWriter::shape` to reconstruct the in-memory model.
+///
+#[allow(missing_debug_implementations)]
+pub struct JsonReader {}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for JsonReader {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl ModelReader for JsonReader {
+    const REPRESENTATION: &'static str = "JSON AST";
+
+    fn read(&mut self, r: &mut impl Read) -> ModelResult<Model> {
+        let top: Value = from_reader(r)
+            .chain_err(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()).to_string())?;
+        let top = top
+            .as_object()
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+
+        let version = top
+            .get(K_SMITHY)
+            .and_then(Value::as_str)
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+        let version = Version::from_str(version)?;
+
+        let mut model = Model::new(version);
+
+        if let Some(shapes) = top.get(K_SHAPES).and_then(Value::as_object) {
+            for (id, value) in shapes {
+                if id == K_METADATA {
+                    self.metadata(&mut model, value)?;
+                    continue;
+                }
+                let shape_id = self.shape_id(id)?;
+                let shape = self.shape(shape_id, value)?;
+                model.add_shape(shape);
+            }
+        }
+
+        Ok(model)
+    }
+}
+
+impl JsonReader {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn metadata(&self, model: &mut Model, value: &Value) -> ModelResult<()> {
+        let meta_map = value
+            .as_object()
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+        for (key, value) in meta_map {
+            model.add_metadata(key.clone(), self.value(value)?);
+        }
+        Ok(())
+    }
+
+    fn shape_id(&self, s: &str) -> ModelResult<ShapeID> {
+        ShapeID::from_str(s)
+            .chain_err(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()).to_string())
+    }
+
+    fn shape(&self, id: ShapeID, value: &Value) -> ModelResult<TopLevelShape> {
+        let shape_map = value
+            .as_object()
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+
+        let shape_type = shape_map
+            .get(K_TYPE)
+            .and_then(Value::as_str)
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+
+        let body = match shape_type {
+            V_LIST => {
+                let target = self.reference(shape_map, K_MEMBER)?;
+                ShapeKind::List(atelier_core::model::shapes::ListOrSet::from(target))
+            }
+            V_SET => {
+                let target = self.reference(shape_map, K_MEMBER)?;
+                ShapeKind::Set(atelier_core::model::shapes::ListOrSet::from(target))
+            }
+            V_MAP => {
+                let key = self.reference(shape_map, K_KEY)?;
+                let value = self.reference(shape_map, K_VALUE)?;
+                ShapeKind::Map(atelier_core::model::shapes::Map::new(key, value))
+            }
+            V_STRUCTURE => ShapeKind::Structure(self.structure_or_union(&id, shape_map)?),
+            V_UNION => ShapeKind::Union(self.structure_or_union(&id, shape_map)?),
+            V_SERVICE => ShapeKind::Service(self.service(shape_map)?),
+            V_OPERATION => ShapeKind::Operation(self.operation(shape_map)?),
+            V_RESOURCE => ShapeKind::Resource(self.resource(shape_map)?),
+            V_APPLY => ShapeKind::Unresolved,
+            other => {
+                let simple = Simple::from_str(other)
+                    .chain_err(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()).to_string())?;
+                ShapeKind::Simple(simple)
+            }
+        };
+
+        let mut shape = TopLevelShape::new(id, body);
+        if let Some(traits) = shape_map.get(K_TRAITS) {
+            for a_trait in self.traits(traits)? {
+                shape.apply_trait(a_trait);
+            }
+        }
+        Ok(shape)
+    }
+
+    fn structure_or_union(
+        &self,
+        id: &ShapeID,
+        shape_map: &Map<String, Value>,
+    ) -> ModelResult<StructureOrUnion> {
+        let mut body = StructureOrUnion::new();
+        if let Some(members) = shape_map.get(K_MEMBERS).and_then(Value::as_object) {
+            for (name, value) in members {
+                let member_map = value
+                    .as_object()
+                    .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+                let target = member_map
+                    .get(K_TARGET)
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+                let target = self.shape_id(target)?;
+                // `JsonWriter` keys each member by its full member shape ID; a bare member name
+                // is also accepted.
+                let member_id = match ShapeID::from_str(name) {
+                    Ok(member_id) if member_id.member_name().is_some() => member_id,
+                    _ => id.make_member(name.parse().chain_err(|| {
+                        ErrorKind::Deserialization(FILE_EXTENSION.to_string()).to_string()
+                    })?),
+                };
+                let mut member = MemberShape::new(member_id, target);
+                if let Some(traits) = member_map.get(K_TRAITS) {
+                    for a_trait in self.traits(traits)? {
+                        member.apply_trait(a_trait);
+                    }
+                }
+                let _ = body.add_a_member(member);
+            }
+        }
+        Ok(body)
+    }
+
+    fn service(&self, shape_map: &Map<String, Value>) -> ModelResult<Service> {
+        let version = shape_map
+            .get(K_VERSION)
+            .and_then(Value::as_str)
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+        let mut service = Service::new(version);
+        for target in self.reference_array(shape_map, K_OPERATIONS)? {
+            service.add_operation(target);
+        }
+        for target in self.reference_array(shape_map, K_RESOURCES)? {
+            service.add_resource(target);
+        }
+        Ok(service)
+    }
+
+    fn operation(&self, shape_map: &Map<String, Value>) -> ModelResult<Operation> {
+        let mut operation = Operation::default();
+        if let Some(value) = shape_map.get(K_INPUT) {
+            operation.set_input(self.reference_value(value)?);
+        }
+        if let Some(value) = shape_map.get(K_OUTPUT) {
+            operation.set_output(self.reference_value(value)?);
+        }
+        for target in self.reference_array(shape_map, K_ERRORS)? {
+            operation.add_error(target);
+        }
+        Ok(operation)
+    }
+
+    fn resource(&self, shape_map: &Map<String, Value>) -> ModelResult<Resource> {
+        let mut resource = Resource::default();
+        if let Some(identifiers) = shape_map.get(K_IDENTIFIERS).and_then(Value::as_object) {
+            for (name, value) in identifiers {
+                let target = value
+                    .as_str()
+                    .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+                resource.add_identifier(name.clone(), NodeValue::String(target.to_string()));
+            }
+        }
+        if let Some(value) = shape_map.get(K_CREATE) {
+            resource.set_create(self.reference_value(value)?);
+        }
+        if let Some(value) = shape_map.get(K_PUT) {
+            resource.set_put(self.reference_value(value)?);
+        }
+        if let Some(value) = shape_map.get(K_READ) {
+            resource.set_read(self.reference_value(value)?);
+        }
+        if let Some(value) = shape_map.get(K_UPDATE) {
+            resource.set_update(self.reference_value(value)?);
+        }
+        if let Some(value) = shape_map.get(K_DELETE) {
+            resource.set_delete(self.reference_value(value)?);
+        }
+        if let Some(value) = shape_map.get(K_LIST) {
+            resource.set_list(self.reference_value(value)?);
+        }
+        for target in self.reference_array(shape_map, K_OPERATIONS)? {
+            resource.add_operation(target);
+        }
+        for target in self.reference_array(shape_map, K_COLLECTION_OPERATIONS)? {
+            resource.add_collection_operation(target);
+        }
+        for target in self.reference_array(shape_map, K_RESOURCES)? {
+            resource.add_resource(target);
+        }
+        Ok(resource)
+    }
+
+    fn traits(&self, value: &Value) -> ModelResult<Vec<AppliedTrait>> {
+        let trait_map = value
+            .as_object()
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+        let mut result = Vec::new();
+        for (id, value) in trait_map {
+            let id = self.shape_id(id)?;
+            let a_trait = if value.is_object() && value.as_object().unwrap().is_empty() {
+                AppliedTrait::new(id)
+            } else {
+                AppliedTrait::with_value(id, self.value(value)?)
+            };
+            result.push(a_trait);
+        }
+        Ok(result)
+    }
+
+    fn value(&self, value: &Value) -> ModelResult<NodeValue> {
+        Ok(match value {
+            Value::Null => NodeValue::None,
+            Value::Bool(v) => NodeValue::Boolean(*v),
+            Value::String(v) => NodeValue::String(v.clone()),
+            Value::Number(v) => {
+                if let Some(v) = v.as_i64() {
+                    NodeValue::Number(Number::Integer(v))
+                } else {
+                    NodeValue::Number(Number::Float(v.as_f64().ok_or_else(|| {
+                        ErrorKind::Deserialization(FILE_EXTENSION.to_string())
+                    })?))
+                }
+            }
+            Value::Array(v) => {
+                let mut result = Vec::new();
+                for v in v {
+                    result.push(self.value(v)?);
+                }
+                NodeValue::Array(result)
+            }
+            Value::Object(v) => {
+                let mut result = std::collections::HashMap::new();
+                for (k, v) in v {
+                    let _ = result.insert(k.clone(), self.value(v)?);
+                }
+                NodeValue::Object(result)
+            }
+        })
+    }
+
+    fn reference(&self, object: &Map<String, Value>, key: &str) -> ModelResult<ShapeID> {
+        let value = object
+            .get(key)
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+        self.reference_value(value)
+    }
+
+    fn reference_value(&self, value: &Value) -> ModelResult<ShapeID> {
+        let target = value
+            .as_object()
+            .and_then(|o| o.get(K_TARGET))
+            .and_then(Value::as_str)
+            .ok_or_else(|| ErrorKind::Deserialization(FILE_EXTENSION.to_string()))?;
+        self.shape_id(target)
+    }
+
+    fn reference_array(
+        &self,
+        object: &Map<String, Value>,
+        key: &str,
+    ) -> ModelResult<Vec<ShapeID>> {
+        match object.get(key).and_then(Value::as_array) {
+            None => Ok(Vec::new()),
+            Some(array) => {
+                let mut result = Vec::new();
+                for value in array {
+                    result.push(self.reference_value(value)?);
+                }
+                Ok(result)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::JsonWriter;
+    use atelier_core::io::{read_model_from_string, write_model_to_string};
+    use atelier_core::model::shapes::Operation;
+    use atelier_core::model::NamespaceID;
+
+    #[test]
+    fn round_trips_resource_collection_operations_and_sub_resources() {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let mut model = Model::new(Version::V10);
+
+        let collection_op = TopLevelShape::new(
+            namespace.make_shape("ListThings".parse().unwrap()),
+            ShapeKind::Operation(Operation::default()),
+        );
+        let sub_resource = TopLevelShape::new(
+            namespace.make_shape("SubThing".parse().unwrap()),
+            ShapeKind::Resource(Resource::default()),
+        );
+
+        let mut resource = Resource::default();
+        resource.add_collection_operation(collection_op.id().clone());
+        resource.add_resource(sub_resource.id().clone());
+        let resource = TopLevelShape::new(
+            namespace.make_shape("Thing".parse().unwrap()),
+            ShapeKind::Resource(resource),
+        );
+        let thing_id = resource.id().clone();
+
+        model.add_shape(resource);
+        model.add_shape(collection_op);
+        model.add_shape(sub_resource);
+
+        let json = write_model_to_string(&mut JsonWriter::new(false), &model).unwrap();
+        let read_back = read_model_from_string(&mut JsonReader::new(), json).unwrap();
+
+        assert_eq!(model.shape(&thing_id), read_back.shape(&thing_id));
+    }
+}
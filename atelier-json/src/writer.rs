@@ -1,10 +1,11 @@
 use crate::syntax::*;
 use crate::FILE_EXTENSION;
 use atelier_core::error::{ErrorKind, Result as ModelResult, ResultExt};
-use atelier_core::io::ModelWriter;
+use atelier_core::io::{write_model_to_string, ModelWriter};
 use atelier_core::model::shapes::{AppliedTrait, HasTraits, MemberShape, ShapeKind, TopLevelShape};
 use atelier_core::model::values::{Number, Value as NodeValue};
 use atelier_core::model::{HasIdentity, Model, ShapeID};
+use sha2::{Digest, Sha256};
 use serde_json::{to_writer, to_writer_pretty, Map, Number as JsonNumber, Value};
 use std::io::Write;
 
@@ -18,6 +19,7 @@ use std::io::Write;
 #[allow(missing_debug_implementations)]
 pub struct JsonWriter {
     pretty_print: bool,
+    canonical: bool,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -28,6 +30,7 @@ impl Default for JsonWriter {
     fn default() -> Self {
         Self {
             pretty_print: false,
+            canonical: false,
         }
     }
 }
@@ -43,11 +46,17 @@ impl ModelWriter for JsonWriter {
 
         let _ = top.insert(K_SHAPES.to_string(), self.shapes(model));
 
+        let top = if self.canonical {
+            canonicalize(Value::Object(top))
+        } else {
+            Value::Object(top)
+        };
+
         if self.pretty_print {
-            to_writer_pretty(w, &Value::Object(top))
+            to_writer_pretty(w, &top)
                 .chain_err(|| ErrorKind::Serialization(FILE_EXTENSION.to_string()).to_string())
         } else {
-            to_writer(w, &Value::Object(top))
+            to_writer(w, &top)
                 .chain_err(|| ErrorKind::Serialization(FILE_EXTENSION.to_string()).to_string())
         }
     }
@@ -55,7 +64,23 @@ impl ModelWriter for JsonWriter {
 
 impl<'a> JsonWriter {
     pub fn new(pretty_print: bool) -> Self {
-        Self { pretty_print }
+        Self {
+            pretty_print,
+            canonical: false,
+        }
+    }
+
+    ///
+    /// Create a writer that produces the canonical JSON AST form: object keys (shapes, members,
+    /// traits, metadata) and the operations/resources/errors reference arrays are sorted into a
+    /// total order that does not depend on the model's in-memory iteration order, so two
+    /// semantically equal models always serialize byte-for-byte the same.
+    ///
+    pub fn canonical() -> Self {
+        Self {
+            pretty_print: false,
+            canonical: true,
+        }
     }
 
     fn shapes(&self, model: &Model) -> Value {
@@ -178,7 +203,7 @@ impl<'a> JsonWriter {
                 }
                 if v.has_collection_operations() {
                     let _ = shape_map.insert(
-                        K_OPERATIONS.to_string(),
+                        K_COLLECTION_OPERATIONS.to_string(),
                         Value::Array(
                             v.collection_operations()
                                 .map(|o| self.reference(o))
@@ -188,7 +213,7 @@ impl<'a> JsonWriter {
                 }
                 if v.has_resources() {
                     let _ = shape_map.insert(
-                        K_COLLECTION_OPERATIONS.to_string(),
+                        K_RESOURCES.to_string(),
                         Value::Array(v.resources().map(|o| self.reference(o)).collect()),
                     );
                 }
@@ -256,3 +281,150 @@ impl<'a> JsonWriter {
         Value::Object(shape_map)
     }
 }
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serialize `model` in the canonical JSON AST form and return the hex-encoded SHA-256 digest of
+/// the result. Because the canonical form imposes a total, iteration-independent order, this
+/// digest changes if and only if the model's semantic content changes.
+///
+pub fn digest(model: &Model) -> ModelResult<String> {
+    let content = write_model_to_string(&mut JsonWriter::canonical(), model)?;
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The object keys under which a shape stores an array of `{ "target": ... }` reference objects.
+/// Only arrays found under one of these keys have a meaningful, order-independent identity; every
+/// other array (trait values, `enum` member lists, metadata, ...) is semantically ordered and must
+/// be left as-is.
+///
+const REFERENCE_ARRAY_KEYS: &[&str] = &[K_OPERATIONS, K_RESOURCES, K_ERRORS, K_COLLECTION_OPERATIONS];
+
+///
+/// Recursively impose a total order on a JSON AST value: object keys are sorted lexically, and
+/// arrays of `{ "target": ... }` reference objects found under a [`REFERENCE_ARRAY_KEYS`] key
+/// (operations/resources/errors/collection_operations lists) are sorted by their target shape ID
+/// so the result does not depend on in-memory iteration order. Other arrays are left in place,
+/// since their order can be semantically meaningful (e.g. an `enum` trait's value list).
+///
+fn canonicalize(value: Value) -> Value {
+    canonicalize_keyed(value, None, false)
+}
+
+///
+/// `in_value` is set once inside a `traits` or `metadata` object, where no array is reordered.
+///
+fn canonicalize_keyed(value: Value, key: Option<&str>, in_value: bool) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut sorted: std::collections::BTreeMap<String, Value> = Default::default();
+            for (k, v) in map {
+                let nested_in_value = in_value || k == K_TRAITS || k == K_METADATA;
+                let canonical = canonicalize_keyed(v, Some(&k), nested_in_value);
+                let _ = sorted.insert(k, canonical);
+            }
+            let mut object: Map<String, Value> = Default::default();
+            for (k, v) in sorted {
+                let _ = object.insert(k, v);
+            }
+            Value::Object(object)
+        }
+        Value::Array(mut array) => {
+            array = array
+                .into_iter()
+                .map(|v| canonicalize_keyed(v, None, in_value))
+                .collect();
+            if !in_value && key.map_or(false, |k| REFERENCE_ARRAY_KEYS.contains(&k)) {
+                array.sort_by(|a, b| reference_sort_key(a).cmp(&reference_sort_key(b)));
+            }
+            Value::Array(array)
+        }
+        other => other,
+    }
+}
+
+fn reference_sort_key(value: &Value) -> String {
+    value
+        .as_object()
+        .and_then(|o| o.get(K_TARGET))
+        .and_then(Value::as_str)
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use atelier_core::model::shapes::Operation;
+    use atelier_core::model::NamespaceID;
+    use atelier_core::Version;
+    use serde_json::json;
+
+    fn model_with_shapes_in_order(names: &[&str]) -> Model {
+        let namespace: NamespaceID = "example.motd".parse().unwrap();
+        let mut model = Model::new(Version::V10);
+        for name in names {
+            model.add_shape(TopLevelShape::new(
+                namespace.make_shape(name.parse().unwrap()),
+                ShapeKind::Operation(Operation::default()),
+            ));
+        }
+        model
+    }
+
+    #[test]
+    fn digest_is_independent_of_shape_insertion_order() {
+        let forward = model_with_shapes_in_order(&["Alpha", "Beta", "Gamma"]);
+        let reverse = model_with_shapes_in_order(&["Gamma", "Beta", "Alpha"]);
+
+        assert_eq!(digest(&forward).unwrap(), digest(&reverse).unwrap());
+    }
+
+    #[test]
+    fn digest_changes_when_model_content_changes() {
+        let original = model_with_shapes_in_order(&["Alpha", "Beta"]);
+        let changed = model_with_shapes_in_order(&["Alpha", "Beta", "Gamma"]);
+
+        assert_ne!(digest(&original).unwrap(), digest(&changed).unwrap());
+    }
+
+    #[test]
+    fn canonicalize_sorts_reference_arrays_but_leaves_other_arrays_as_is() {
+        let value = json!({
+            "operations": [
+                { "target": "example.motd#Zulu" },
+                { "target": "example.motd#Alpha" }
+            ],
+            "traits": {
+                "smithy.api#enum": [
+                    { "value": "ZULU" },
+                    { "value": "ALPHA" }
+                ]
+            }
+        });
+
+        let canonical = canonicalize(value);
+
+        assert_eq!(
+            canonical["operations"],
+            json!([
+                { "target": "example.motd#Alpha" },
+                { "target": "example.motd#Zulu" }
+            ])
+        );
+        assert_eq!(
+            canonical["traits"]["smithy.api#enum"],
+            json!([{ "value": "ZULU" }, { "value": "ALPHA" }])
+        );
+    }
+}